@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use common_arrow::arrow::io::ipc::write::Compression as ArrowIpcCompression;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_arrow::arrow::io::ipc::IpcField;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::sessions::QueryContext;
+
+/// IPC compression negotiated for `FlightData` crossing the exchange between nodes.
+/// Configured through the `flight_compression` session setting; the receiving
+/// `ExchangeMerge` decodes it transparently since arrow2 records the codec in
+/// the IPC stream itself.
+///
+/// Only the codec is configurable: arrow2's IPC `WriteOptions` takes a bare
+/// `Compression` codec with no level knob, so there's nothing to wire a
+/// `flight_compression_level` setting into today.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlightCompression {
+    None,
+    Lz4Frame,
+    Zstd,
+}
+
+impl FlightCompression {
+    pub fn from_setting(value: &str) -> Result<FlightCompression> {
+        match value.to_ascii_lowercase().as_str() {
+            "none" | "" => Ok(FlightCompression::None),
+            "lz4" | "lz4_frame" => Ok(FlightCompression::Lz4Frame),
+            "zstd" => Ok(FlightCompression::Zstd),
+            other => Err(ErrorCode::BadArguments(format!(
+                "unknown flight_compression '{}', expect one of: none, lz4, zstd",
+                other
+            ))),
+        }
+    }
+
+    fn into_arrow(self) -> Option<ArrowIpcCompression> {
+        match self {
+            FlightCompression::None => None,
+            FlightCompression::Lz4Frame => Some(ArrowIpcCompression::LZ4),
+            FlightCompression::Zstd => Some(ArrowIpcCompression::ZSTD),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct SerializeParams {
+    pub options: WriteOptions,
+    pub ipc_fields: Vec<IpcField>,
+}
+
+#[derive(Clone)]
+pub struct MergeExchangeParams {
+    pub ctx: Arc<QueryContext>,
+    pub query_id: String,
+    pub destination_id: String,
+    pub ipc_fields: Vec<IpcField>,
+}
+
+impl MergeExchangeParams {
+    pub fn create_serialize_params(&self) -> Result<SerializeParams> {
+        let settings = self.ctx.get_settings();
+        let compression = FlightCompression::from_setting(&settings.get_flight_compression()?)?;
+
+        Ok(SerializeParams {
+            ipc_fields: self.ipc_fields.clone(),
+            options: WriteOptions {
+                compression: compression.into_arrow(),
+            },
+        })
+    }
+}
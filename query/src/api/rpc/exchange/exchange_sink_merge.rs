@@ -1,10 +1,12 @@
 use common_exception::ErrorCode;
+use std::collections::VecDeque;
 use std::sync::Arc;
-use async_channel::{Sender, TrySendError};
+use async_channel::{SendError, Sender, TrySendError};
 use common_arrow::arrow::io::flight::serialize_batch;
 use common_arrow::arrow_format::flight::data::FlightData;
 use common_datablocks::DataBlock;
 use crate::api::rpc::exchange::exchange_params::{MergeExchangeParams, SerializeParams};
+use crate::api::rpc::exchange::secure_channel::SecureChannelSender;
 use crate::pipelines::new::processors::port::{InputPort, OutputPort};
 use crate::pipelines::new::processors::Processor;
 use crate::pipelines::new::processors::processor::{Event, ProcessorPtr};
@@ -12,15 +14,39 @@ use crate::sessions::QueryContext;
 
 use common_exception::Result;
 
+/// the peer-facing half of the exchange: either a bare channel, or (when the
+/// `enable_secure_exchange` setting is on) one tunnelled through a per-query
+/// encrypted, authenticated [`SecureChannelSender`].
+enum PeerPublisher {
+    Plain(Sender<FlightData>),
+    Secure(SecureChannelSender),
+}
+
+impl PeerPublisher {
+    fn try_send(&self, data: FlightData) -> std::result::Result<(), TrySendError<FlightData>> {
+        match self {
+            PeerPublisher::Plain(sender) => sender.try_send(data),
+            PeerPublisher::Secure(sender) => sender.try_send(data),
+        }
+    }
+
+    async fn send(&self, data: FlightData) -> std::result::Result<(), SendError<FlightData>> {
+        match self {
+            PeerPublisher::Plain(sender) => sender.send(data).await,
+            PeerPublisher::Secure(sender) => sender.send(data).await,
+        }
+    }
+}
+
 pub struct ExchangeMergeSink {
     ctx: Arc<QueryContext>,
 
     input: Arc<InputPort>,
     input_data: Option<DataBlock>,
-    output_data: Option<FlightData>,
+    output_data: VecDeque<FlightData>,
     serialize_params: SerializeParams,
     exchange_params: MergeExchangeParams,
-    peer_endpoint_publisher: Option<Sender<FlightData>>,
+    peer_endpoint_publisher: Option<PeerPublisher>,
 }
 
 impl ExchangeMergeSink {
@@ -32,10 +58,29 @@ impl ExchangeMergeSink {
             exchange_params,
             serialize_params,
             input_data: None,
-            output_data: None,
+            output_data: VecDeque::new(),
             peer_endpoint_publisher: None,
         })))
     }
+
+    /// wrap the raw peer channel in a [`SecureChannelSender`] when
+    /// `enable_secure_exchange` is on, otherwise use it as-is.
+    fn wrap_publisher(&self, sender: Sender<FlightData>) -> Result<PeerPublisher> {
+        if !self.ctx.get_settings().get_enable_secure_exchange()? {
+            return Ok(PeerPublisher::Plain(sender));
+        }
+
+        let query_id = &self.exchange_params.query_id;
+        let destination_id = &self.exchange_params.destination_id;
+        let session_key = self
+            .ctx
+            .get_exchange_manager()
+            .handshake_with_peer(query_id, destination_id)?;
+        Ok(PeerPublisher::Secure(SecureChannelSender::create(
+            sender,
+            session_key,
+        )))
+    }
 }
 
 #[async_trait::async_trait]
@@ -45,22 +90,26 @@ impl Processor for ExchangeMergeSink {
     }
 
     fn event(&mut self) -> common_exception::Result<Event> {
-        if let Some(output) = self.output_data.take() {
+        if !self.output_data.is_empty() {
             if self.peer_endpoint_publisher.is_none() {
                 let query_id = &self.exchange_params.query_id;
                 let destination_id = &self.exchange_params.destination_id;
                 let exchange_manager = self.ctx.get_exchange_manager();
-                self.peer_endpoint_publisher = Some(exchange_manager.get_fragment_sink(query_id, destination_id)?);
+                let sender = exchange_manager.get_fragment_sink(query_id, destination_id)?;
+                self.peer_endpoint_publisher = Some(self.wrap_publisher(sender)?);
             }
 
             let mut need_async_send = false;
             if let Some(publisher) = &self.peer_endpoint_publisher {
-                match publisher.try_send(output) {
-                    Ok(_) => { /* do nothing*/ }
-                    Err(TrySendError::Closed(_)) => { return Ok(Event::Finished); }
-                    Err(TrySendError::Full(value)) => {
-                        need_async_send = true;
-                        self.output_data = Some(value);
+                while let Some(output) = self.output_data.pop_front() {
+                    match publisher.try_send(output) {
+                        Ok(_) => { /* do nothing*/ }
+                        Err(TrySendError::Closed(_)) => { return Ok(Event::Finished); }
+                        Err(TrySendError::Full(value)) => {
+                            need_async_send = true;
+                            self.output_data.push_front(value);
+                            break;
+                        }
                     }
                 }
             }
@@ -98,21 +147,19 @@ impl Processor for ExchangeMergeSink {
             let ipc_fields = &self.serialize_params.ipc_fields;
             let (dicts, values) = serialize_batch(&chunks, ipc_fields, options);
 
-            if !dicts.is_empty() {
-                return Err(ErrorCode::UnImplement("DatabendQuery does not implement dicts."));
-            }
-
-            // FlightData
-            self.output_data = Some(values);
+            // dictionaries must reach the peer before the record batch that references them,
+            // so they're queued first, in the order `serialize_batch` produced them (IPC order).
+            self.output_data.extend(dicts);
+            self.output_data.push_back(values);
         }
 
         Ok(())
     }
 
     async fn async_process(&mut self) -> common_exception::Result<()> {
-        if let Some(mut output_data) = self.output_data.take() {
-            if let Some(sender) = &self.peer_endpoint_publisher {
-                if let Err(_) = sender.send(output_data).await {
+        if let Some(publisher) = &self.peer_endpoint_publisher {
+            while let Some(output_data) = self.output_data.pop_front() {
+                if let Err(_) = publisher.send(output_data).await {
                     return Err(ErrorCode::TokioError(
                         "Cannot send flight data to endpoint, because sender is closed."
                     ));
@@ -0,0 +1,125 @@
+use std::sync::Arc;
+use async_channel::Receiver;
+use common_arrow::arrow_format::flight::data::FlightData;
+use crate::api::rpc::exchange::exchange_params::MergeExchangeParams;
+use crate::api::rpc::exchange::secure_channel::SecureChannelReceiver;
+use crate::pipelines::new::processors::port::OutputPort;
+use crate::pipelines::new::processors::Processor;
+use crate::pipelines::new::processors::processor::{Event, ProcessorPtr};
+use crate::sessions::QueryContext;
+
+use common_exception::Result;
+
+/// the peer-facing half of the exchange on the receiving side: either a bare
+/// channel, or (when the `enable_secure_exchange` setting is on) one tunnelled
+/// through a per-query [`SecureChannelReceiver`] that opens what the peer's
+/// `SecureChannelSender` sealed.
+enum PeerSubscriber {
+    Plain(Receiver<FlightData>),
+    Secure(Receiver<FlightData>, SecureChannelReceiver),
+}
+
+impl PeerSubscriber {
+    /// pull the next `FlightData` off the peer channel. `Ok(None)` means the peer
+    /// channel closed normally (end of stream); a decrypt/authentication failure on
+    /// a secure channel is a real error and must not be confused with the former —
+    /// silently treating a tampered payload as "stream ended" would surface a
+    /// truncated-but-successful query result instead of failing it.
+    async fn recv(&self) -> Result<Option<FlightData>> {
+        match self {
+            PeerSubscriber::Plain(receiver) => Ok(receiver.recv().await.ok()),
+            PeerSubscriber::Secure(receiver, opener) => match receiver.recv().await {
+                Ok(sealed) => opener.open(sealed).map(Some),
+                Err(_) => Ok(None),
+            },
+        }
+    }
+}
+
+/// the receiving counterpart of [`super::exchange_sink_merge::ExchangeMergeSink`]:
+/// pulls the `FlightData` fragments a peer shipped for this query off the local
+/// channel, opening them with the matching `SecureChannelReceiver` when the
+/// sender sealed them, and hands plaintext `FlightData` downstream to be
+/// deserialized back into `DataBlock`s.
+pub struct ExchangeSourceReader {
+    ctx: Arc<QueryContext>,
+
+    output: Arc<OutputPort>,
+    output_data: Option<FlightData>,
+    exchange_params: MergeExchangeParams,
+    peer_endpoint_subscriber: Option<PeerSubscriber>,
+}
+
+impl ExchangeSourceReader {
+    pub fn try_create(ctx: Arc<QueryContext>, output: Arc<OutputPort>, exchange_params: MergeExchangeParams) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(Box::new(ExchangeSourceReader {
+            ctx,
+            output,
+            exchange_params,
+            output_data: None,
+            peer_endpoint_subscriber: None,
+        })))
+    }
+
+    /// wrap the raw peer channel in a [`SecureChannelReceiver`] when
+    /// `enable_secure_exchange` is on, otherwise use it as-is.
+    fn wrap_subscriber(&self, receiver: Receiver<FlightData>) -> Result<PeerSubscriber> {
+        if !self.ctx.get_settings().get_enable_secure_exchange()? {
+            return Ok(PeerSubscriber::Plain(receiver));
+        }
+
+        let query_id = &self.exchange_params.query_id;
+        let source_id = &self.exchange_params.destination_id;
+        let session_key = self
+            .ctx
+            .get_exchange_manager()
+            .handshake_with_peer(query_id, source_id)?;
+        Ok(PeerSubscriber::Secure(
+            receiver,
+            SecureChannelReceiver::create(session_key),
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for ExchangeSourceReader {
+    fn name(&self) -> &'static str {
+        "ExchangeSource"
+    }
+
+    fn event(&mut self) -> common_exception::Result<Event> {
+        if self.output.is_finished() {
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(output_data) = self.output_data.take() {
+            self.output.push_data(Ok(output_data));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.peer_endpoint_subscriber.is_none() {
+            let query_id = &self.exchange_params.query_id;
+            let source_id = &self.exchange_params.destination_id;
+            let exchange_manager = self.ctx.get_exchange_manager();
+            let receiver = exchange_manager.get_fragment_source(query_id, source_id)?;
+            self.peer_endpoint_subscriber = Some(self.wrap_subscriber(receiver)?);
+        }
+
+        Ok(Event::Async)
+    }
+
+    async fn async_process(&mut self) -> common_exception::Result<()> {
+        if let Some(subscriber) = &self.peer_endpoint_subscriber {
+            match subscriber.recv().await? {
+                Some(data) => self.output_data = Some(data),
+                None => self.output.finish(),
+            }
+        }
+
+        Ok(())
+    }
+}
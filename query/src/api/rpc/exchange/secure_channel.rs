@@ -0,0 +1,221 @@
+use async_channel::SendError;
+use async_channel::Sender;
+use async_channel::TrySendError;
+use chacha20poly1305::aead::Aead;
+use chacha20poly1305::aead::NewAead;
+use chacha20poly1305::ChaCha20Poly1305;
+use chacha20poly1305::Key;
+use chacha20poly1305::Nonce;
+use common_arrow::arrow_format::flight::data::FlightData;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use ed25519_dalek::Signature;
+use ed25519_dalek::Signer;
+use ed25519_dalek::SigningKey;
+use ed25519_dalek::Verifier;
+use ed25519_dalek::VerifyingKey;
+use hkdf::Hkdf;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use sha2::Sha256;
+use x25519_dalek::PublicKey;
+use x25519_dalek::StaticSecret;
+
+/// long-lived identity of a node in the cluster, used to authenticate handshakes.
+/// each node keeps one of these around for the lifetime of the process: the
+/// Diffie-Hellman key is a `StaticSecret` (not an `EphemeralSecret`) so it survives
+/// being used in more than one handshake, and an Ed25519 keypair signs the
+/// Diffie-Hellman public key so a peer can tell it really came from this node.
+pub struct NodeIdentity {
+    pub node_id: String,
+    dh_secret: StaticSecret,
+    pub dh_public_key: PublicKey,
+    signing_key: SigningKey,
+    pub verifying_key: VerifyingKey,
+}
+
+impl NodeIdentity {
+    pub fn create(node_id: impl Into<String>) -> NodeIdentity {
+        let dh_secret = StaticSecret::new(OsRng);
+        let dh_public_key = PublicKey::from(&dh_secret);
+        let signing_key = SigningKey::generate(&mut OsRng);
+        let verifying_key = signing_key.verifying_key();
+        NodeIdentity {
+            node_id: node_id.into(),
+            dh_secret,
+            dh_public_key,
+            signing_key,
+            verifying_key,
+        }
+    }
+
+    /// sign this node's Diffie-Hellman public key with its long-lived identity key,
+    /// so a peer holding `verifying_key` (exchanged out-of-band, e.g. via cluster
+    /// membership metadata) can authenticate it before completing [`Self::handshake`].
+    pub fn sign_handshake(&self) -> (PublicKey, Signature) {
+        (self.dh_public_key, self.signing_key.sign(self.dh_public_key.as_bytes()))
+    }
+
+    /// perform the node's half of the handshake: authenticate the peer's Diffie-Hellman
+    /// public key against its signature and long-lived `peer_verifying_key`, then derive
+    /// the symmetric session key from the shared secret through HKDF (the raw
+    /// Diffie-Hellman output is never used as a cipher key directly).
+    pub fn handshake(
+        &self,
+        peer_public_key: PublicKey,
+        peer_signature: &Signature,
+        peer_verifying_key: &VerifyingKey,
+    ) -> Result<SessionKey> {
+        peer_verifying_key
+            .verify(peer_public_key.as_bytes(), peer_signature)
+            .map_err(|_| {
+                ErrorCode::UnknownException(
+                    "secure channel handshake failed: peer's public key has an invalid signature, possible MITM",
+                )
+            })?;
+
+        let shared_secret = self.dh_secret.diffie_hellman(&peer_public_key);
+        let mut session_key_bytes = [0u8; 32];
+        Hkdf::<Sha256>::new(None, shared_secret.as_bytes())
+            .expand(b"datafuse-exchange-session-key", &mut session_key_bytes)
+            .map_err(|_| {
+                ErrorCode::UnknownException("failed to derive exchange session key via HKDF")
+            })?;
+
+        Ok(SessionKey(*Key::from_slice(&session_key_bytes)))
+    }
+}
+
+/// symmetric key derived once per `(query_id, peer)` pair from a Diffie-Hellman
+/// handshake between two [`NodeIdentity`]s.
+#[derive(Clone)]
+pub struct SessionKey(Key);
+
+/// wraps a plaintext `Sender<FlightData>` so that payloads are sealed with an
+/// authenticated cipher before `try_send`/`send`, and the receiving fragment
+/// sink opens them with the same session key. The processor code talking to
+/// this behaves exactly like talking to `Sender<FlightData>` directly.
+#[derive(Clone)]
+pub struct SecureChannelSender {
+    inner: Sender<FlightData>,
+    cipher: ChaCha20Poly1305,
+}
+
+impl SecureChannelSender {
+    pub fn create(inner: Sender<FlightData>, session_key: SessionKey) -> SecureChannelSender {
+        SecureChannelSender {
+            inner,
+            cipher: ChaCha20Poly1305::new(&session_key.0),
+        }
+    }
+
+    pub fn try_send(&self, data: FlightData) -> std::result::Result<(), TrySendError<FlightData>> {
+        let sealed = self.seal(data.clone());
+        match self.inner.try_send(sealed) {
+            Ok(_) => Ok(()),
+            // on backpressure, hand the caller back the *plaintext* value it gave us,
+            // matching `Sender<FlightData>::try_send`'s contract.
+            Err(TrySendError::Full(_)) => Err(TrySendError::Full(data)),
+            Err(TrySendError::Closed(_)) => Err(TrySendError::Closed(data)),
+        }
+    }
+
+    pub async fn send(&self, data: FlightData) -> std::result::Result<(), SendError<FlightData>> {
+        let sealed = self.seal(data.clone());
+        self.inner.send(sealed).await.map_err(|_| SendError(data))
+    }
+
+    /// seal a single `FlightData` message: `data_header` (the Arrow IPC schema and
+    /// record-batch metadata) is sealed together with `data_body` rather than left
+    /// in cleartext, so neither is readable or tamperable in transit; a fresh
+    /// random nonce is prepended to each message since every `try_send`/`send`
+    /// call is independently authenticated.
+    fn seal(&self, data: FlightData) -> FlightData {
+        let mut nonce_bytes = [0u8; 12];
+        OsRng.fill_bytes(&mut nonce_bytes);
+        let nonce = Nonce::from_slice(&nonce_bytes);
+
+        let plaintext = frame_header_and_body(&data.data_header, &data.data_body);
+        let ciphertext = self
+            .cipher
+            .encrypt(nonce, plaintext.as_ref())
+            .expect("chacha20poly1305 encryption is infallible for this payload size");
+
+        let mut framed = Vec::with_capacity(nonce_bytes.len() + ciphertext.len());
+        framed.extend_from_slice(&nonce_bytes);
+        framed.extend_from_slice(&ciphertext);
+
+        FlightData {
+            data_header: Default::default(),
+            data_body: framed.into(),
+            ..data
+        }
+    }
+}
+
+/// pack `header` and `body` into a single length-prefixed buffer so they can be
+/// sealed (and later split back apart by [`SecureChannelReceiver::open`]) as one
+/// AEAD message: `[header_len: u32 LE][header][body]`.
+fn frame_header_and_body(header: &[u8], body: &[u8]) -> Vec<u8> {
+    let mut framed = Vec::with_capacity(4 + header.len() + body.len());
+    framed.extend_from_slice(&(header.len() as u32).to_le_bytes());
+    framed.extend_from_slice(header);
+    framed.extend_from_slice(body);
+    framed
+}
+
+/// receiving side of a [`SecureChannelSender`] tunnel: opens sealed `FlightData`
+/// payloads coming off a fragment sink before they reach the deserializer.
+pub struct SecureChannelReceiver {
+    cipher: ChaCha20Poly1305,
+}
+
+impl SecureChannelReceiver {
+    pub fn create(session_key: SessionKey) -> SecureChannelReceiver {
+        SecureChannelReceiver {
+            cipher: ChaCha20Poly1305::new(&session_key.0),
+        }
+    }
+
+    pub fn open(&self, data: FlightData) -> Result<FlightData> {
+        if data.data_body.len() < 12 {
+            return Err(ErrorCode::UnknownException(
+                "secure channel payload too short to contain a nonce",
+            ));
+        }
+
+        let (nonce_bytes, ciphertext) = data.data_body.split_at(12);
+        let nonce = Nonce::from_slice(nonce_bytes);
+        let plaintext = self.cipher.decrypt(nonce, ciphertext).map_err(|_| {
+            ErrorCode::UnknownException(
+                "failed to authenticate secure channel payload, peer key mismatch or tampering",
+            )
+        })?;
+
+        let (data_header, data_body) = split_header_and_body(&plaintext)?;
+        Ok(FlightData {
+            data_header: data_header.to_vec().into(),
+            data_body: data_body.to_vec().into(),
+            ..data
+        })
+    }
+}
+
+/// undo [`frame_header_and_body`], failing if the length prefix doesn't fit the
+/// buffer it's packed into (a tampered or corrupt payload that still happened to
+/// pass AEAD authentication, which framing alone can't rule out).
+fn split_header_and_body(framed: &[u8]) -> Result<(&[u8], &[u8])> {
+    if framed.len() < 4 {
+        return Err(ErrorCode::UnknownException(
+            "secure channel payload too short to contain a header length",
+        ));
+    }
+    let (header_len, rest) = framed.split_at(4);
+    let header_len = u32::from_le_bytes(header_len.try_into().unwrap()) as usize;
+    if rest.len() < header_len {
+        return Err(ErrorCode::UnknownException(
+            "secure channel payload's header length is inconsistent with its size",
+        ));
+    }
+    Ok(rest.split_at(header_len))
+}
@@ -22,8 +22,11 @@ use std::any::Any;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use common_catalog::plan::PartInfo;
+use common_catalog::plan::PartInfoPtr;
 use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
 use common_catalog::plan::PushDownInfo;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
@@ -35,12 +38,90 @@ use common_storage::DataOperator;
 use iceberg_rs::model::table::TableMetadataV2;
 
 use crate::converters::meta_iceberg_to_databend;
+use crate::manifest::DataFile;
+use crate::manifest::ManifestContentType;
+use crate::manifest::ManifestEntry;
+use crate::manifest::ManifestEntryStatus;
+use crate::manifest::ManifestFile;
 
 /// directory containing metadata files
 const META_DIR: &str = "metadata";
 /// file marking the current version of metadata file
 const META_PTR: &str = "metadata/version_hint.text";
 
+/// configuration to reach an [Iceberg REST catalog](https://iceberg.apache.org/spec/#rest-catalog)
+/// for [`IcebergTable::try_create_table_from_rest`].
+#[derive(Debug, Clone)]
+pub struct RestCatalogConfig {
+    /// base URL of the catalog server, e.g. `https://catalog.example.com`
+    pub uri: String,
+    /// resource prefix used in `/v1/{prefix}/...` routes, if the catalog is multi-tenant
+    pub prefix: Option<String>,
+    /// bearer token used to authenticate requests, if the catalog requires auth
+    pub token: Option<String>,
+}
+
+impl RestCatalogConfig {
+    fn load_table_url(&self, namespace: &str, table_name: &str) -> String {
+        match &self.prefix {
+            Some(prefix) => format!(
+                "{}/v1/{}/namespaces/{}/tables/{}",
+                self.uri.trim_end_matches('/'),
+                prefix.trim_matches('/'),
+                namespace,
+                table_name
+            ),
+            None => format!(
+                "{}/v1/namespaces/{}/tables/{}",
+                self.uri.trim_end_matches('/'),
+                namespace,
+                table_name
+            ),
+        }
+    }
+}
+
+/// selects which snapshot an [`IcebergTable`] scans, for time-travel reads
+/// (`SELECT ... AT (SNAPSHOT => ...)`). Defaults to the table's `current-snapshot-id`.
+#[derive(Debug, Clone, Copy)]
+pub enum SnapshotSelector {
+    /// scan the snapshot with this id.
+    SnapshotId(i64),
+    /// scan the snapshot with the greatest timestamp less than or equal to this one
+    /// (milliseconds since the epoch), resolved against the table's `snapshot-log`.
+    AsOf(i64),
+}
+
+/// body of a REST catalog `LoadTableResult` response; only the fields we need are modelled
+#[derive(Debug, serde::Deserialize)]
+struct LoadTableResponse {
+    #[serde(rename = "metadata-location")]
+    metadata_location: Option<String>,
+    metadata: TableMetadataV2,
+}
+
+/// best-effort: turn an absolute Iceberg `location`/`metadata-location` URI into
+/// the path under `catalog_root` it corresponds to, by dropping the URI scheme
+/// and the authority/bucket segment — `catalog_root`'s object store is expected
+/// to be rooted there.
+fn rel_path_from_location(location: &str) -> Option<String> {
+    let without_scheme = match location.split_once("://") {
+        Some((_, rest)) => rest,
+        None => location,
+    };
+    let (_, path) = without_scheme.split_once('/')?;
+    let path = path.trim_end_matches('/');
+    (!path.is_empty()).then(|| path.to_string())
+}
+
+/// derive a table's `rel_path` from a REST catalog's `metadata-location` (the URI
+/// of the `vN.metadata.json` file, not the table directory) by dropping its
+/// trailing `metadata/<file>.json` segment first.
+fn rel_path_from_metadata_location(metadata_location: &str) -> Option<String> {
+    let (table_location, _) = metadata_location.rsplit_once("/metadata/")?;
+    rel_path_from_location(table_location)
+}
+
 /// accessor wrapper as a table
 /// # Note
 /// The operator pointing to the directory holding the table directory.
@@ -60,25 +141,32 @@ pub struct IcebergTable {
     name: String,
     /// relative path of current table to the catalog
     rel_path: String,
+    /// the table's absolute `location`, as recorded in its own metadata; used to
+    /// resolve the absolute manifest/data file paths Iceberg records into paths
+    /// relative to `catalog_root`
+    location: String,
     /// root of the catalog
     catalog_root: Arc<DataOperator>,
     /// table metadata
     manifests: TableMetadataV2,
+    /// which snapshot to scan; `None` means the table's `current-snapshot-id`
+    snapshot_selector: Option<SnapshotSelector>,
     /// table information
     info: TableInfo,
 }
 
 impl IcebergTable {
-    /// create a new table on the table directory
+    /// create a new table on the table directory, optionally pinned to a snapshot
+    /// other than the current one for time-travel reads
     pub async fn try_create_table_from_read(
         catalog: &str,
         tenant: &str,
         database: &str,
         table_name: &str,
         catalog_root: Arc<DataOperator>,
+        snapshot_selector: Option<SnapshotSelector>,
     ) -> Result<IcebergTable> {
         let meta_ptr_file = format!("{}/{}/{}", database, table_name, META_PTR);
-        // only care about data in the latest snapshot for now :)
         // find version_hint.txt, version number can be get from it.
         let hint = catalog_root.object(&meta_ptr_file);
         let version: u64 = String::from_utf8(hint.read().await.map_err(|e| {
@@ -113,7 +201,91 @@ impl IcebergTable {
                 ))
             })?;
 
-        // construct table info
+        Ok(Self::from_metadata(
+            catalog,
+            tenant,
+            database,
+            table_name,
+            format!("{}/{}", database, table_name),
+            catalog_root,
+            metadata,
+            snapshot_selector,
+        ))
+    }
+
+    /// create a new table by asking an Iceberg REST catalog for its metadata,
+    /// rather than discovering `metadata/version_hint.text` on the object store directly.
+    ///
+    /// `catalog_root` still roots the object store used to read the table's data and
+    /// manifest files, which the REST catalog's `metadata-location` is expected to live under.
+    pub async fn try_create_table_from_rest(
+        catalog: &str,
+        tenant: &str,
+        database: &str,
+        table_name: &str,
+        catalog_root: Arc<DataOperator>,
+        rest_catalog: &RestCatalogConfig,
+        snapshot_selector: Option<SnapshotSelector>,
+    ) -> Result<IcebergTable> {
+        let url = rest_catalog.load_table_url(database, table_name);
+
+        let mut request = reqwest::Client::new().get(&url);
+        if let Some(token) = &rest_catalog.token {
+            request = request.bearer_auth(token);
+        }
+
+        let response = request.send().await.map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("failed to reach REST catalog {}: {:?}", url, e))
+        })?;
+        let response = response.error_for_status().map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("REST catalog rejected {}: {:?}", url, e))
+        })?;
+
+        let body: LoadTableResponse = response.json().await.map_err(|e| {
+            ErrorCode::ReadTableDataError(format!(
+                "invalid REST catalog response from {}: {:?}",
+                url, e
+            ))
+        })?;
+
+        // a managed REST catalog's table need not live under `{database}/{table}` of
+        // `catalog_root` the way a directly-discovered table does: derive the actual
+        // data root from the metadata it returned instead of assuming that layout.
+        // `body.metadata.location` is the table's base directory and is what we want;
+        // `body.metadata_location` is the URI of the `vN.metadata.json` *file* itself,
+        // so it only yields a usable `rel_path` once its trailing `metadata/<file>.json`
+        // segment is stripped off.
+        let rel_path = rel_path_from_location(&body.metadata.location)
+            .or_else(|| {
+                body.metadata_location
+                    .as_deref()
+                    .and_then(rel_path_from_metadata_location)
+            })
+            .unwrap_or_else(|| format!("{}/{}", database, table_name));
+
+        Ok(Self::from_metadata(
+            catalog,
+            tenant,
+            database,
+            table_name,
+            rel_path,
+            catalog_root,
+            body.metadata,
+            snapshot_selector,
+        ))
+    }
+
+    /// build the `IcebergTable`/`TableInfo` shared by every metadata discovery path
+    fn from_metadata(
+        catalog: &str,
+        tenant: &str,
+        database: &str,
+        table_name: &str,
+        rel_path: String,
+        catalog_root: Arc<DataOperator>,
+        metadata: TableMetadataV2,
+        snapshot_selector: Option<SnapshotSelector>,
+    ) -> IcebergTable {
         let info = TableInfo {
             ident: TableIdent::new(0, 0),
             desc: format!("IcebergTable: '{}'.'{}'", database, table_name),
@@ -123,15 +295,176 @@ impl IcebergTable {
             ..Default::default()
         };
 
-        // finish making table
-        Ok(Self {
+        Self {
             database: database.to_string(),
             name: table_name.to_string(),
+            rel_path,
+            location: metadata.location.clone(),
             catalog_root,
             manifests: metadata,
+            snapshot_selector,
             info,
+        }
+    }
+
+    /// read a file off the catalog root, given an absolute Iceberg location
+    /// (`manifest_list`, `manifest_path`, or a data/delete file's `file_path`) —
+    /// Iceberg always records these as fully-qualified URIs/paths, never as paths
+    /// relative to the table directory, so they must be resolved against this
+    /// table's own `location` rather than string-joined onto `rel_path`.
+    async fn read_table_object(&self, absolute_path: &str) -> Result<Vec<u8>> {
+        let object = self.catalog_root.object(&self.resolve_absolute_path(absolute_path));
+        object.read().await.map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("invalid object {}: {:?}", object.name(), e))
         })
     }
+
+    /// resolve an absolute Iceberg location to the path `catalog_root` expects,
+    /// by replacing this table's own absolute `location` prefix with `rel_path`
+    /// (the same directory, expressed relative to `catalog_root`).
+    fn resolve_absolute_path(&self, absolute_path: &str) -> String {
+        let location = self.location.trim_end_matches('/');
+        match absolute_path.strip_prefix(location) {
+            Some(suffix) => format!("{}{}", self.rel_path, suffix),
+            None => absolute_path.to_string(),
+        }
+    }
+
+    /// the snapshot this table scans: either `current-snapshot-id`, or the one
+    /// picked by `self.snapshot_selector` for a time-travel read.
+    fn scan_snapshot(&self) -> Result<&iceberg_rs::model::table::Snapshot> {
+        let snapshot_id = match self.snapshot_selector {
+            None => self.manifests.current_snapshot_id.ok_or_else(|| {
+                ErrorCode::ReadTableDataError("iceberg table has no current snapshot")
+            })?,
+            Some(SnapshotSelector::SnapshotId(snapshot_id)) => snapshot_id,
+            Some(SnapshotSelector::AsOf(timestamp_ms)) => self.snapshot_id_as_of(timestamp_ms)?,
+        };
+
+        self.manifests
+            .snapshots
+            .iter()
+            .flatten()
+            .find(|snapshot| snapshot.snapshot_id == snapshot_id)
+            .ok_or_else(|| {
+                ErrorCode::ReadTableDataError(format!(
+                    "snapshot {} not found in snapshots list",
+                    snapshot_id
+                ))
+            })
+    }
+
+    /// the id of the snapshot whose timestamp is the greatest one `<= timestamp_ms`,
+    /// per the table's `snapshot-log`.
+    fn snapshot_id_as_of(&self, timestamp_ms: i64) -> Result<i64> {
+        self.manifests
+            .snapshot_log
+            .iter()
+            .flatten()
+            .filter(|log_entry| log_entry.timestamp_ms <= timestamp_ms)
+            .max_by_key(|log_entry| log_entry.timestamp_ms)
+            .map(|log_entry| log_entry.snapshot_id)
+            .ok_or_else(|| {
+                ErrorCode::ReadTableDataError(format!(
+                    "no snapshot as of timestamp {}",
+                    timestamp_ms
+                ))
+            })
+    }
+
+    /// whether a data file might still contain rows matching the push-down filters.
+    /// missing bounds for a referenced column means we cannot prune, so we keep the file.
+    /// this only consults `lower_bounds`/`upper_bounds`; pruning by partition spec
+    /// (transforms applied to `data_file.partition`) is not implemented and is out
+    /// of scope here, so a file can only ever be pruned on column value bounds.
+    fn data_file_may_match(&self, data_file: &DataFile, push_downs: Option<&PushDownInfo>) -> bool {
+        let filters = match push_downs.and_then(|p| p.filters.as_ref()) {
+            Some(filters) if !filters.is_empty() => filters,
+            _ => return true,
+        };
+
+        filters
+            .iter()
+            .all(|filter| pruning::predicate_may_match(self, filter, data_file))
+    }
+
+    /// resolve a column name to its Iceberg field id via the table's current schema
+    fn field_id_by_name(&self, name: &str) -> Option<i32> {
+        let current_schema_id = self.manifests.current_schema_id;
+        self.manifests
+            .schemas
+            .iter()
+            .flatten()
+            .find(|schema| schema.schema_id == current_schema_id)
+            .and_then(|schema| schema.fields.iter().find(|field| field.name == name))
+            .map(|field| field.id)
+    }
+
+    /// read all manifest entries reachable from the scanned snapshot's manifest-list,
+    /// resolving each entry's inherited data sequence number from the `ManifestFile`
+    /// that listed it (never from the scanned snapshot — a manifest can be reused
+    /// across snapshots after its own data was sequenced).
+    async fn read_current_manifest_entries(&self) -> Result<Vec<ManifestEntry>> {
+        let snapshot = self.scan_snapshot()?;
+        let manifest_list_bytes = self.read_table_object(&snapshot.manifest_list).await?;
+        let manifest_files = ManifestFile::read_avro(&manifest_list_bytes)?;
+
+        let mut entries = Vec::new();
+        for manifest_file in manifest_files {
+            let manifest_bytes = self.read_table_object(&manifest_file.manifest_path).await?;
+            for mut entry in ManifestEntry::read_avro(&manifest_bytes)? {
+                entry.sequence_number =
+                    Some(entry.data_sequence_number(manifest_file.sequence_number));
+                entries.push(entry);
+            }
+        }
+        Ok(entries)
+    }
+
+    /// collect the v2 delete files (`POSITION_DELETES` / `EQUALITY_DELETES`) among
+    /// `entries`, paired with their (already-resolved, see
+    /// [`Self::read_current_manifest_entries`]) data sequence number so callers can
+    /// apply the sequence-number rule of [`Self::applicable_deletes`].
+    fn collect_delete_files(&self, entries: &[ManifestEntry]) -> Vec<(i64, IcebergDeleteFile)> {
+        entries
+            .iter()
+            .filter(|entry| entry.status != ManifestEntryStatus::Deleted)
+            .filter_map(|entry| {
+                let data_file = &entry.data_file;
+                let delete_file = match data_file.content {
+                    ManifestContentType::Data => return None,
+                    ManifestContentType::PositionDeletes => IcebergDeleteFile::Position {
+                        file_path: self.resolve_absolute_path(&data_file.file_path),
+                    },
+                    ManifestContentType::EqualityDeletes => IcebergDeleteFile::Equality {
+                        file_path: self.resolve_absolute_path(&data_file.file_path),
+                        equality_ids: data_file.equality_ids.clone().unwrap_or_default(),
+                    },
+                };
+                Some((entry.sequence_number.unwrap_or_default(), delete_file))
+            })
+            .collect()
+    }
+
+    /// the delete files among `delete_files` that apply to a data file added at
+    /// `data_sequence_number`. Per the v2 spec, a position delete applies to data
+    /// files added at or before it (`<=`), while an equality delete only removes
+    /// rows visible as of snapshots strictly before it (`<`) — a data file added at
+    /// the exact same sequence number as an equality delete was written alongside
+    /// it, not before it, so the delete doesn't apply.
+    fn applicable_deletes(
+        delete_files: &[(i64, IcebergDeleteFile)],
+        data_sequence_number: i64,
+    ) -> Vec<IcebergDeleteFile> {
+        delete_files
+            .iter()
+            .filter(|(delete_sequence_number, delete_file)| match delete_file {
+                IcebergDeleteFile::Position { .. } => data_sequence_number <= *delete_sequence_number,
+                IcebergDeleteFile::Equality { .. } => data_sequence_number < *delete_sequence_number,
+            })
+            .map(|(_, delete_file)| delete_file.clone())
+            .collect()
+    }
 }
 
 #[async_trait]
@@ -155,8 +488,269 @@ impl Table for IcebergTable {
     async fn read_partitions(
         &self,
         _ctx: Arc<dyn TableContext>,
-        _push_downs: Option<PushDownInfo>,
+        push_downs: Option<PushDownInfo>,
     ) -> Result<(PartStatistics, Partitions)> {
-        todo!()
+        let entries = self.read_current_manifest_entries().await?;
+        let delete_files = self.collect_delete_files(&entries);
+
+        let mut statistics = PartStatistics::default();
+        let mut parts: Vec<PartInfoPtr> = Vec::new();
+
+        for entry in entries {
+            if entry.status == ManifestEntryStatus::Deleted {
+                continue;
+            }
+
+            let data_file = &entry.data_file;
+            if data_file.content != ManifestContentType::Data {
+                // delete files (position/equality) were already collected above.
+                continue;
+            }
+
+            if !self.data_file_may_match(data_file, push_downs.as_ref()) {
+                continue;
+            }
+
+            let data_sequence_number = entry.sequence_number.unwrap_or_default();
+            let deletes = Self::applicable_deletes(&delete_files, data_sequence_number);
+
+            statistics.read_rows += data_file.record_count as usize;
+            statistics.read_bytes += data_file.file_size_in_bytes as usize;
+            parts.push(Arc::new(Box::new(IcebergPartInfo::new(
+                self.resolve_absolute_path(&data_file.file_path),
+                data_file.record_count as usize,
+                deletes,
+            ))));
+        }
+
+        statistics.partitions_total = parts.len();
+        statistics.partitions_scanned = parts.len();
+
+        Ok((
+            statistics,
+            Partitions::create(PartitionsShuffleKind::Seq, parts),
+        ))
+    }
+}
+
+/// a single data file this table should scan, as handed out by [`IcebergTable::read_partitions`]
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Debug)]
+pub struct IcebergPartInfo {
+    pub path: String,
+    pub rows_count: usize,
+    /// v2 delete files that apply to this data file; the read pipeline must
+    /// mask out the rows these describe before returning this partition's data.
+    pub deletes: Vec<IcebergDeleteFile>,
+}
+
+impl IcebergPartInfo {
+    pub fn new(path: String, rows_count: usize, deletes: Vec<IcebergDeleteFile>) -> Self {
+        IcebergPartInfo {
+            path,
+            rows_count,
+            deletes,
+        }
+    }
+}
+
+/// a v2 delete file associated with a data file by [`IcebergTable::applicable_deletes`]
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Debug)]
+pub enum IcebergDeleteFile {
+    /// a `POSITION_DELETES` file: rows are `(file_path, pos)` pairs naming the data
+    /// file and row index to drop.
+    Position { file_path: String },
+    /// an `EQUALITY_DELETES` file: rows are a projection onto `equality_ids`' columns,
+    /// and any data row whose values match a delete row is dropped.
+    Equality {
+        file_path: String,
+        equality_ids: Vec<i32>,
+    },
+}
+
+#[typetag::serde(name = "iceberg")]
+impl PartInfo for IcebergPartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        match info.as_any().downcast_ref::<IcebergPartInfo>() {
+            None => false,
+            Some(other) => self == other,
+        }
+    }
+}
+
+/// best-effort pruning of [`DataFile`]s against push-down filters using the
+/// column bounds recorded in the manifest. Anything we cannot confidently
+/// evaluate (unknown function, missing bound, non-numeric/string literal)
+/// falls back to "may match" so we never drop rows we shouldn't.
+mod pruning {
+    use common_expression::RemoteExpr;
+    use common_expression::Scalar;
+
+    use super::DataFile;
+    use super::IcebergTable;
+
+    pub fn predicate_may_match(
+        table: &IcebergTable,
+        filter: &RemoteExpr<String>,
+        data_file: &DataFile,
+    ) -> bool {
+        match extract_comparison(filter) {
+            Some((column, op, scalar)) => match table.field_id_by_name(&column) {
+                Some(field_id) => may_match_bound(data_file, field_id, op, &scalar),
+                // unknown column: can't reason about it, keep the file.
+                None => true,
+            },
+            // anything we can't decompose into `column op literal`: keep the file.
+            None => true,
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    pub enum CompareOp {
+        Eq,
+        Lt,
+        Lte,
+        Gt,
+        Gte,
+    }
+
+    /// pattern-match a filter of shape `column <op> literal` (or the mirrored
+    /// `literal <op> column`), the only shape cheap enough to prune with.
+    fn extract_comparison(expr: &RemoteExpr<String>) -> Option<(String, CompareOp, Scalar)> {
+        let RemoteExpr::FunctionCall {
+            function_name,
+            args,
+            ..
+        } = expr
+        else {
+            return None;
+        };
+
+        let op = match function_name.as_str() {
+            "eq" => CompareOp::Eq,
+            "lt" => CompareOp::Lt,
+            "lte" => CompareOp::Lte,
+            "gt" => CompareOp::Gt,
+            "gte" => CompareOp::Gte,
+            _ => return None,
+        };
+
+        match args.as_slice() {
+            [RemoteExpr::ColumnRef { id, .. }, RemoteExpr::Constant { scalar, .. }] => {
+                Some((id.clone(), op, scalar.clone()))
+            }
+            [RemoteExpr::Constant { scalar, .. }, RemoteExpr::ColumnRef { id, .. }] => {
+                Some((id.clone(), flip(op), scalar.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    fn flip(op: CompareOp) -> CompareOp {
+        match op {
+            CompareOp::Eq => CompareOp::Eq,
+            CompareOp::Lt => CompareOp::Gt,
+            CompareOp::Lte => CompareOp::Gte,
+            CompareOp::Gt => CompareOp::Lt,
+            CompareOp::Gte => CompareOp::Lte,
+        }
+    }
+
+    fn may_match_bound(data_file: &DataFile, field_id: i32, op: CompareOp, scalar: &Scalar) -> bool {
+        let lower = data_file
+            .lower_bounds
+            .as_ref()
+            .and_then(|bounds| bounds.get(&field_id))
+            .and_then(|bytes| decode_bound(bytes, scalar));
+        let upper = data_file
+            .upper_bounds
+            .as_ref()
+            .and_then(|bounds| bounds.get(&field_id))
+            .and_then(|bytes| decode_bound(bytes, scalar));
+
+        // missing bounds on either side: we cannot prove there is no match.
+        let (Some(lower), Some(upper)) = (lower, upper) else {
+            return true;
+        };
+        let value = match decode_scalar(scalar) {
+            Some(value) => value,
+            None => return true,
+        };
+
+        // a bound of a different kind than the literal isn't something we can
+        // reason about (and must never be decided by enum discriminant order),
+        // so treat it like a missing bound: we cannot prove there is no match.
+        let (Some(lower_vs_value), Some(upper_vs_value)) = (
+            lower.partial_cmp_same_kind(&value),
+            upper.partial_cmp_same_kind(&value),
+        ) else {
+            return true;
+        };
+
+        use std::cmp::Ordering::Greater;
+        use std::cmp::Ordering::Less;
+        match op {
+            CompareOp::Eq => lower_vs_value != Greater && upper_vs_value != Less,
+            CompareOp::Lt => lower_vs_value == Less,
+            CompareOp::Lte => lower_vs_value != Greater,
+            CompareOp::Gt => upper_vs_value == Greater,
+            CompareOp::Gte => upper_vs_value != Less,
+        }
+    }
+
+    /// comparable projection of a bound/literal; only numeric and UTF-8 string
+    /// types are supported, matching Iceberg's single-value binary serialization.
+    enum Bound {
+        Int(i64),
+        Float(f64),
+        Str(String),
+    }
+
+    impl Bound {
+        /// order `self` against `other`, but only within the same kind: an `Int`
+        /// compared against a `Float` (or either against a `Str`) can't be
+        /// compared correctly (ordering by enum discriminant would silently pick
+        /// an arbitrary answer), so callers must treat `None` as "may match".
+        fn partial_cmp_same_kind(&self, other: &Bound) -> Option<std::cmp::Ordering> {
+            match (self, other) {
+                (Bound::Int(a), Bound::Int(b)) => a.partial_cmp(b),
+                (Bound::Float(a), Bound::Float(b)) => a.partial_cmp(b),
+                (Bound::Str(a), Bound::Str(b)) => a.partial_cmp(b),
+                _ => None,
+            }
+        }
+    }
+
+    fn decode_scalar(scalar: &Scalar) -> Option<Bound> {
+        match scalar {
+            Scalar::Number(n) => n.as_i64().map(Bound::Int).or_else(|| n.as_f64().map(Bound::Float)),
+            Scalar::String(s) => Some(Bound::Str(String::from_utf8_lossy(s).to_string())),
+            _ => None,
+        }
+    }
+
+    /// decode a manifest column bound, using `like` (the query literal being
+    /// compared against) to tell whether the bound is a UTF-8 string, an int or a
+    /// float/double, since Iceberg's single-value binary encoding is just raw
+    /// little-endian bytes with no embedded type tag. Any other literal kind
+    /// yields `None`.
+    fn decode_bound(bytes: &[u8], like: &Scalar) -> Option<Bound> {
+        match like {
+            Scalar::Number(n) if n.as_i64().is_some() || n.as_u64().is_some() => match bytes.len() {
+                4 => Some(Bound::Int(i32::from_le_bytes(bytes.try_into().ok()?) as i64)),
+                8 => Some(Bound::Int(i64::from_le_bytes(bytes.try_into().ok()?))),
+                _ => None,
+            },
+            Scalar::Number(_) => match bytes.len() {
+                4 => Some(Bound::Float(f32::from_le_bytes(bytes.try_into().ok()?) as f64)),
+                8 => Some(Bound::Float(f64::from_le_bytes(bytes.try_into().ok()?))),
+                _ => None,
+            },
+            Scalar::String(_) => std::str::from_utf8(bytes).ok().map(|s| Bound::Str(s.to_string())),
+            _ => None,
+        }
     }
 }